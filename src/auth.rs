@@ -0,0 +1,39 @@
+use std::panic::AssertUnwindSafe;
+
+use pdf_extract::{output_doc, pdf::file::FileOptions, PlainTextOutput};
+use wasm_bindgen::prelude::*;
+
+use crate::error::OcrError;
+
+/// Extract text from a password-protected PDF, decrypting with `password`
+/// (tried as both the user and owner password) before walking the content
+/// streams. For PDFs that aren't encrypted, `password` is simply unused.
+///
+/// Returns `OcrError::EncryptedPdf` if the document is encrypted and
+/// `password` doesn't unlock it.
+///
+/// As with [`crate::ocr_result`], the `catch_unwind` below only recovers
+/// panics when the crate is built with `panic = "unwind"` — under the
+/// wasm default of `panic = "abort"` a panic still aborts the module, so
+/// the `from_panic_payload` branch depends on that build setting.
+#[wasm_bindgen]
+pub fn ocr_with_password(bytes: &[u8], password: &str) -> Result<String, JsValue> {
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let document = FileOptions::cached()
+            .password(password.as_bytes())
+            .load(bytes)
+            .map_err(|e| OcrError::from_message(&e.to_string()).into_js_value())?;
+
+        let mut text = String::new();
+        let mut output = PlainTextOutput::new(&mut text);
+        output_doc(&document, &mut output)
+            .map_err(|e| OcrError::from_output_error(e).into_js_value())?;
+
+        Ok(text)
+    }));
+
+    match result {
+        Ok(inner) => inner,
+        Err(payload) => Err(OcrError::from_panic_payload(payload).into_js_value()),
+    }
+}
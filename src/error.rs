@@ -0,0 +1,121 @@
+use std::fmt;
+
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+/// Error surfaced to JS callers when PDF text extraction fails.
+///
+/// `pdf_extract` (and the PDF parsers it wraps) distinguish a handful of
+/// common failure shapes by panic message rather than by typed error, so we
+/// pattern-match the panic payload to recover a useful variant instead of
+/// letting the panic cross the wasm boundary and poison the module.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum OcrError {
+    /// The PDF requires a password before its content streams can be read.
+    EncryptedPdf,
+    /// The underlying parser rejected the document itself.
+    ParseError(String),
+    /// The text layer references a glyph that has no entry in the font's
+    /// char map, so the run can't be converted to text.
+    MissingGlyphMapping,
+    /// Anything else, including panics we couldn't classify.
+    Other(String),
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::EncryptedPdf => write!(f, "PDF is encrypted and requires a password"),
+            OcrError::ParseError(msg) => write!(f, "failed to parse PDF: {msg}"),
+            OcrError::MissingGlyphMapping => {
+                write!(f, "text layer references a glyph with no char map entry")
+            }
+            OcrError::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl OcrError {
+    /// Classify a `pdf_extract::OutputError` into one of our variants.
+    pub fn from_output_error(err: pdf_extract::OutputError) -> Self {
+        Self::from_message(&err.to_string())
+    }
+
+    /// Classify a panic payload captured via `catch_unwind`.
+    pub fn from_panic_payload(payload: Box<dyn std::any::Any + Send>) -> Self {
+        let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        Self::from_message(&msg)
+    }
+
+    pub(crate) fn from_message(msg: &str) -> Self {
+        if msg.contains("Encrypt")
+            || msg.contains("encrypted")
+            || msg.contains("password")
+            || msg.contains("decrypt")
+        {
+            OcrError::EncryptedPdf
+        } else if msg.contains("missing char") && msg.contains("in map") {
+            OcrError::MissingGlyphMapping
+        } else if msg.contains("UnexpectedPrimitive")
+            || msg.contains("no entry found for key")
+            || msg.contains("uninitialized")
+        {
+            OcrError::ParseError(msg.to_string())
+        } else {
+            OcrError::Other(msg.to_string())
+        }
+    }
+
+    pub fn into_js_value(self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self).unwrap_or_else(|_| JsValue::from_str(&self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(msg: &str, expected: &str) -> bool {
+        matches!(
+            (OcrError::from_message(msg), expected),
+            (OcrError::EncryptedPdf, "encrypted")
+                | (OcrError::ParseError(_), "parse")
+                | (OcrError::MissingGlyphMapping, "glyph")
+                | (OcrError::Other(_), "other")
+        )
+    }
+
+    #[test]
+    fn classifies_encryption_failures() {
+        assert!(matches("the document is Encrypted", "encrypted"));
+        assert!(matches("requires a password to open", "encrypted"));
+        assert!(matches("failed to decrypt stream", "encrypted"));
+    }
+
+    #[test]
+    fn classifies_missing_glyph_mappings() {
+        assert!(matches("missing char 33 in map", "glyph"));
+    }
+
+    #[test]
+    fn classifies_parse_errors() {
+        assert!(matches(
+            "UnexpectedPrimitive { expected: \"Reference\" }",
+            "parse"
+        ));
+        assert!(matches("no entry found for key", "parse"));
+        assert!(matches("attempted to leave type uninitialized", "parse"));
+    }
+
+    #[test]
+    fn falls_back_to_other_for_unrecognized_messages() {
+        assert!(matches("something completely unexpected happened", "other"));
+    }
+}
@@ -0,0 +1,52 @@
+//! Pixel-format helpers for the rasterization path. The rendering backend
+//! hands back BGR(A) buffers; downstream OCR and image crates expect RGBA,
+//! so these just do the channel swap and (for BGR) synthesize a
+//! full-opacity alpha channel. Embedded-image extraction (`images.rs`)
+//! does not use them: it converts RGB and grayscale samples itself.
+
+/// Convert a tightly packed 3-channel BGR buffer into RGBA.
+pub fn bgr_to_rgba(bgr: &[u8]) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(bgr.len() / 3 * 4);
+    for c in bgr.chunks_exact(3) {
+        rgba.extend_from_slice(&[c[2], c[1], c[0], 255]);
+    }
+    rgba
+}
+
+/// Convert a tightly packed 4-channel BGRA buffer into RGBA.
+pub fn bgra_to_rgba(bgra: &[u8]) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(bgra.len());
+    for c in bgra.chunks_exact(4) {
+        rgba.extend_from_slice(&[c[2], c[1], c[0], c[3]]);
+    }
+    rgba
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bgr_to_rgba_swaps_channels_and_adds_opaque_alpha() {
+        let bgr = [10, 20, 30, 40, 50, 60];
+        assert_eq!(bgr_to_rgba(&bgr), vec![30, 20, 10, 255, 60, 50, 40, 255]);
+    }
+
+    #[test]
+    fn bgra_to_rgba_swaps_channels_and_preserves_alpha() {
+        let bgra = [10, 20, 30, 128, 40, 50, 60, 64];
+        assert_eq!(
+            bgra_to_rgba(&bgra),
+            vec![30, 20, 10, 128, 60, 50, 40, 64]
+        );
+    }
+
+    #[test]
+    fn ignores_trailing_bytes_that_dont_fill_a_whole_pixel() {
+        let bgr = [10, 20, 30, 40];
+        assert_eq!(bgr_to_rgba(&bgr), vec![30, 20, 10, 255]);
+
+        let bgra = [10, 20, 30, 40, 50];
+        assert_eq!(bgra_to_rgba(&bgra), vec![30, 20, 10, 40]);
+    }
+}
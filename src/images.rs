@@ -0,0 +1,162 @@
+use pdf_extract::pdf::{
+    content::{Content, Op},
+    file::FileOptions,
+    object::{ColorSpace, XObject},
+};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::OcrError;
+
+/// An embedded raster image pulled out of a page's XObject resources,
+/// decoded to a standard RGBA buffer. `width`/`height` are the image's
+/// pixel dimensions — the ones `rgba_bytes` (`width * height * 4` bytes)
+/// is actually shaped for. `page_x`/`page_y`/`page_w`/`page_h` are the
+/// separate placement rectangle, in PDF points, that the page's content
+/// stream draws the image into.
+#[derive(Debug, Serialize)]
+pub struct ExtractedImage {
+    pub page: u32,
+    pub width: u32,
+    pub height: u32,
+    pub page_x: f64,
+    pub page_y: f64,
+    pub page_w: f64,
+    pub page_h: f64,
+    pub rgba_bytes: Vec<u8>,
+}
+
+/// A PDF content-stream transformation matrix `[a b c d e f]`, applied as
+/// `x' = a*x + c*y + e`, `y' = b*x + d*y + f`.
+#[derive(Clone, Copy)]
+struct Matrix([f64; 6]);
+
+impl Matrix {
+    const IDENTITY: Matrix = Matrix([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    /// Concatenate `self` with a `cm` operand, i.e. `other * self`.
+    fn concat(self, other: [f64; 6]) -> Matrix {
+        let [a1, b1, c1, d1, e1, f1] = other;
+        let [a2, b2, c2, d2, e2, f2] = self.0;
+        Matrix([
+            a1 * a2 + b1 * c2,
+            a1 * b2 + b1 * d2,
+            c1 * a2 + d1 * c2,
+            c1 * b2 + d1 * d2,
+            e1 * a2 + f1 * c2 + e2,
+            e1 * b2 + f1 * d2 + f2,
+        ])
+    }
+
+    /// Placement of the unit image square `[0,1]x[0,1]` this matrix maps
+    /// to on the page, in PDF points, as `(x, y, width, height)` (ignoring
+    /// rotation/skew, which covers the axis-aligned placements `Do` is
+    /// used for in practice). This is independent of the image's pixel
+    /// dimensions.
+    fn image_rect(&self) -> (f64, f64, f64, f64) {
+        let [a, _b, _c, d, e, f] = self.0;
+        (e, f, a.abs(), d.abs())
+    }
+}
+
+/// Walk every page's content stream and pull out embedded raster images
+/// (figures, stamps, scanned insets) invoked via `Do`, decoded to RGBA and
+/// positioned using the CTM in effect at the point each one is drawn. So
+/// callers can save them or run OCR on just the image regions.
+#[wasm_bindgen]
+pub fn extract_images(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let document = FileOptions::cached()
+        .load(bytes)
+        .map_err(|e| OcrError::ParseError(e.to_string()).into_js_value())?;
+
+    let mut images = Vec::new();
+    for (page_num, page) in document.pages().enumerate() {
+        let Ok(page) = page else { continue };
+        let Ok(resources) = page.resources() else {
+            continue;
+        };
+        let Ok(content) = page.content_ops(&document) else {
+            continue;
+        };
+
+        let mut ctm_stack = vec![Matrix::IDENTITY];
+        for op in Content::parse_ops(&content) {
+            match op {
+                Op::Save => {
+                    let top = *ctm_stack.last().unwrap();
+                    ctm_stack.push(top);
+                }
+                Op::Restore => {
+                    if ctm_stack.len() > 1 {
+                        ctm_stack.pop();
+                    }
+                }
+                Op::Transform { matrix } => {
+                    let top = ctm_stack.last_mut().unwrap();
+                    *top = top.concat(matrix);
+                }
+                Op::XObject { name } => {
+                    let Some(xobject_ref) = resources.xobjects.get(&name) else {
+                        continue;
+                    };
+                    let Ok(XObject::Image(image)) = document.get(*xobject_ref) else {
+                        continue;
+                    };
+                    let Ok(data) = image.image_data(&document) else {
+                        continue;
+                    };
+
+                    // Only formats we actually know how to reinterpret as
+                    // RGBA are handled; anything else (CMYK, indexed/palette,
+                    // non-8-bit samples, ...) is skipped rather than
+                    // reinterpreted as BGRA and handed out as corrupt pixels.
+                    let Some(rgba) = (match (image.color_space, image.bits_per_component) {
+                        (Some(ColorSpace::DeviceRGB), Some(8)) => Some(rgb_to_rgba(&data)),
+                        (Some(ColorSpace::DeviceGray), Some(8)) => Some(gray_to_rgba(&data)),
+                        // DeviceCMYK, indexed/palette, and non-8-bit samples
+                        // aren't handled yet — skip rather than misdecode.
+                        _ => None,
+                    }) else {
+                        continue;
+                    };
+
+                    let (page_x, page_y, page_w, page_h) = ctm_stack.last().unwrap().image_rect();
+                    images.push(ExtractedImage {
+                        page: page_num as u32,
+                        width: image.width,
+                        height: image.height,
+                        page_x,
+                        page_y,
+                        page_w,
+                        page_h,
+                        rgba_bytes: rgba,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    serde_wasm_bindgen::to_value(&images)
+        .map_err(|e| OcrError::Other(e.to_string()).into_js_value())
+}
+
+/// Convert a tightly packed 3-channel RGB buffer into RGBA (no channel
+/// swap — unlike the pdfium rasterizer, `DeviceRGB` image samples are
+/// already stored in RGB order).
+fn rgb_to_rgba(rgb: &[u8]) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(rgb.len() / 3 * 4);
+    for c in rgb.chunks_exact(3) {
+        rgba.extend_from_slice(&[c[0], c[1], c[2], 255]);
+    }
+    rgba
+}
+
+/// Convert a single-channel grayscale buffer into RGBA.
+fn gray_to_rgba(gray: &[u8]) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(gray.len() * 4);
+    for &g in gray {
+        rgba.extend_from_slice(&[g, g, g, 255]);
+    }
+    rgba
+}
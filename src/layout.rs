@@ -0,0 +1,163 @@
+use pdf_extract::{output_doc, OutputDev, OutputError, Transform};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// A single run of text captured from the content stream, positioned by
+/// the text matrix in effect when it was drawn. `height` is currently
+/// just a cap-height proxy equal to `font_size` (no descender/ascender
+/// metrics are tracked), so it's redundant with `font_size` for now —
+/// kept as a separate field so a future, more accurate height doesn't
+/// require a breaking schema change.
+#[derive(Debug, Serialize)]
+pub struct TextRun {
+    pub text: String,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub font_size: f64,
+}
+
+type Page = Vec<TextRun>;
+
+/// `OutputDev` implementation that captures each run's position (the
+/// origin of its first character), width and font size instead of
+/// flattening the content stream into a plain string, so callers can
+/// reconstruct columns, tables, and reading order.
+struct LayoutOutput {
+    pages: Vec<Page>,
+    current_word: String,
+    current_run_origin: Option<(f64, f64, f64)>,
+    current_width: f64,
+}
+
+impl LayoutOutput {
+    fn new() -> Self {
+        LayoutOutput {
+            pages: Vec::new(),
+            current_word: String::new(),
+            current_run_origin: None,
+            current_width: 0.0,
+        }
+    }
+
+    fn flush_word(&mut self) {
+        if self.current_word.is_empty() {
+            return;
+        }
+        let (x, y, font_size) = self.current_run_origin.unwrap_or((0.0, 0.0, 0.0));
+        if let Some(page) = self.pages.last_mut() {
+            page.push(TextRun {
+                text: std::mem::take(&mut self.current_word),
+                x,
+                y,
+                width: self.current_width,
+                height: font_size,
+                font_size,
+            });
+        }
+        self.current_run_origin = None;
+        self.current_width = 0.0;
+    }
+}
+
+impl OutputDev for LayoutOutput {
+    fn begin_page(
+        &mut self,
+        _page_num: u32,
+        _media_box: &pdf_extract::MediaBox,
+        _art_box: Option<(f64, f64, f64, f64)>,
+    ) -> Result<(), OutputError> {
+        self.pages.push(Vec::new());
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), OutputError> {
+        self.flush_word();
+        Ok(())
+    }
+
+    fn output_character(
+        &mut self,
+        trm: &Transform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+    ) -> Result<(), OutputError> {
+        if self.current_run_origin.is_none() {
+            self.current_run_origin = Some((trm.m31, trm.m32, font_size));
+        }
+        // `width` is the glyph's advance as a fraction of the em, scaled by
+        // `font_size`; `spacing` (from `Tc`/`Tw`) is already in text-space
+        // units and isn't scaled again. Accumulate both to get the run's
+        // real extent rather than guessing from the character count.
+        self.current_width += width * font_size + spacing;
+        self.current_word.push_str(char);
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), OutputError> {
+        self.flush_word();
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), OutputError> {
+        self.flush_word();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flush_word_is_a_no_op_when_nothing_was_accumulated() {
+        let mut output = LayoutOutput::new();
+        output.pages.push(Vec::new());
+        output.flush_word();
+        assert!(output.pages[0].is_empty());
+    }
+
+    #[test]
+    fn flush_word_pushes_the_accumulated_run_and_resets_state() {
+        let mut output = LayoutOutput::new();
+        output.pages.push(Vec::new());
+        output.current_word = "Hello".to_string();
+        output.current_run_origin = Some((12.0, 34.0, 10.0));
+        output.current_width = 25.0;
+
+        output.flush_word();
+
+        assert_eq!(output.pages[0].len(), 1);
+        let run = &output.pages[0][0];
+        assert_eq!(run.text, "Hello");
+        assert_eq!(run.x, 12.0);
+        assert_eq!(run.y, 34.0);
+        assert_eq!(run.width, 25.0);
+        assert_eq!(run.height, 10.0);
+        assert_eq!(run.font_size, 10.0);
+
+        assert!(output.current_word.is_empty());
+        assert!(output.current_run_origin.is_none());
+        assert_eq!(output.current_width, 0.0);
+    }
+}
+
+/// Extract text with per-page, per-run position and font size, returned as
+/// JSON: an array of pages, each an array of `{ text, x, y, width, height,
+/// font_size }` runs.
+#[wasm_bindgen]
+pub fn ocr_layout(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let document = pdf_extract::pdf::file::FileOptions::cached()
+        .load(bytes)
+        .map_err(|e| crate::error::OcrError::ParseError(e.to_string()).into_js_value())?;
+
+    let mut output = LayoutOutput::new();
+    output_doc(&document, &mut output)
+        .map_err(|e| crate::error::OcrError::from_output_error(e).into_js_value())?;
+
+    serde_wasm_bindgen::to_value(&output.pages)
+        .map_err(|e| crate::error::OcrError::Other(e.to_string()).into_js_value())
+}
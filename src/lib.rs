@@ -1,9 +1,44 @@
-use pdf_extract;
+use std::panic::AssertUnwindSafe;
+
 use wasm_bindgen::prelude::*;
 
+mod auth;
+mod error;
+mod image_utils;
+mod images;
+mod layout;
+mod raster;
+
+use error::OcrError;
+
 #[wasm_bindgen]
 pub fn ocr(bytes: &[u8]) -> String {
     let out = pdf_extract::extract_text_from_mem(&bytes).unwrap();
 
     String::from(out)
 }
+
+/// Fallible counterpart to [`ocr`]. Malformed PDFs routinely make
+/// `pdf_extract` panic (missing glyph mappings, unexpected primitives,
+/// uninitialized reads) rather than return an error, so this wraps the
+/// extraction in `catch_unwind` and reports a structured [`OcrError`]
+/// instead of taking down the wasm module.
+///
+/// `catch_unwind` only recovers a panic under `panic = "unwind"`; the
+/// default wasm target profile is `panic = "abort"`, under which a panic
+/// still aborts the module instead of being caught here. The crate's
+/// `Cargo.toml` **must** set `panic = "unwind"` for the wasm target (or
+/// build profile) for this function's panic recovery to actually work —
+/// without it, this only catches `OutputError`s, not panics.
+#[wasm_bindgen]
+pub fn ocr_result(bytes: &[u8]) -> Result<String, JsValue> {
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        pdf_extract::extract_text_from_mem(bytes)
+    }));
+
+    match result {
+        Ok(Ok(text)) => Ok(text),
+        Ok(Err(err)) => Err(OcrError::from_output_error(err).into_js_value()),
+        Err(payload) => Err(OcrError::from_panic_payload(payload).into_js_value()),
+    }
+}
@@ -0,0 +1,59 @@
+use pdfium_render::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use crate::image_utils::{bgr_to_rgba, bgra_to_rgba};
+
+/// Below this many characters, the embedded text layer is treated as
+/// effectively empty (cover pages, stamps, and the odd stray text box
+/// shouldn't stop a scanned document from falling through to OCR).
+const TEXT_LAYER_MIN_LEN: usize = 32;
+
+/// Extract text from `bytes`, falling back to rasterizing each page at
+/// `dpi` and running OCR over the pixels when the embedded text layer is
+/// empty or too sparse to be useful (scanned documents have no text layer
+/// at all).
+#[wasm_bindgen]
+pub fn ocr_images(bytes: &[u8], dpi: f32) -> String {
+    if let Ok(text) = pdf_extract::extract_text_from_mem(bytes) {
+        if text.trim().chars().count() >= TEXT_LAYER_MIN_LEN {
+            return text;
+        }
+    }
+
+    rasterize_and_recognize(bytes, dpi)
+}
+
+/// Render every page of `bytes` at `dpi` and run OCR over the rasterized
+/// pixels, ignoring whatever embedded text layer is present.
+fn rasterize_and_recognize(bytes: &[u8], dpi: f32) -> String {
+    let pdfium = Pdfium::default();
+    let document = match pdfium.load_pdf_from_byte_slice(bytes, None) {
+        Ok(document) => document,
+        Err(_) => return String::new(),
+    };
+
+    let render_config = PdfRenderConfig::new().scale_page_by_factor(dpi / 72.0);
+
+    let mut out = String::new();
+    for page in document.pages().iter() {
+        let Ok(bitmap) = page.render_with_config(&render_config) else {
+            continue;
+        };
+
+        let rgba = match bitmap.format() {
+            PdfBitmapFormat::BGR => bgr_to_rgba(bitmap.as_raw_bytes()),
+            _ => bgra_to_rgba(bitmap.as_raw_bytes()),
+        };
+
+        out.push_str(&recognize_text(&rgba, bitmap.width() as u32, bitmap.height() as u32));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Run the configured recognizer over a normalized RGBA page image.
+fn recognize_text(rgba: &[u8], width: u32, height: u32) -> String {
+    tesseract::ocr_from_frame(rgba, width as i32, height as i32, 4, width as i32 * 4, "eng")
+        .unwrap_or_default()
+}